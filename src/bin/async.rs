@@ -1,3 +1,4 @@
+use read_jeelink::Message;
 use read_jeelink::SerialPortListener;
 use read_jeelink::BAUD_RATE;
 use tokio_serial::SerialPortBuilderExt;
@@ -14,9 +15,19 @@ async fn main() -> tokio_serial::Result<()> {
 
     let mut reader = SerialPortListener::new(port);
 
-    while let Ok(frame) = reader.read_frame().await {
-        match frame {
-            Some(frame) => println!("{frame}"),
+    while let Ok(message) = reader.read_frame().await {
+        match message {
+            Some(Message::LaCrosse(frame)) => println!(
+                "Sensor {:2}: Temperatur {:4}, Humidity {:2}",
+                frame.id, frame.temperature, frame.humidity
+            ),
+            Some(Message::DeviceInfo(info)) => println!(
+                "Device info: firmware {}, radio {}, frequency {} kHz, toggle {}",
+                info.firmware_version, info.radio, info.frequency_khz, info.toggle
+            ),
+            Some(Message::Other(prefix, body)) => {
+                println!("Unhandled message (prefix {prefix}): {body}")
+            }
             None => (),
         }
     }
@@ -1,22 +1,27 @@
-use read_jeelink::{SerialPortListener, BAUD_RATE, TIMEOUT};
+use read_jeelink::sync::SerialListener;
+use read_jeelink::Message;
 
 static DEVICE: &str = "/dev/tty.usbserial-AL006PX8";
 
 fn main() -> std::io::Result<()> {
     println!("Open port on device");
-    let mut reader = SerialPortListener::new(
-        serialport::new(DEVICE, BAUD_RATE)
-            .timeout(TIMEOUT)
-            .open_native()?,
-    );
+    let listener = SerialListener::bind(DEVICE)?;
     println!("Ready to read");
     loop {
-        match reader.read_frame() {
-            Ok(Some(frame)) => println!("{frame}"),
-            Ok(None) => (),
-            Err(e) => eprintln!("{}", e),
-            //     if let Some(frame) = frame {
-            //     }
+        for message in listener.accept()? {
+            match message {
+                Message::LaCrosse(frame) => println!(
+                    "Sensor {:2}: Temperatur {:4}, Humidity {:2}",
+                    frame.id, frame.temperature, frame.humidity
+                ),
+                Message::DeviceInfo(info) => println!(
+                    "Device info: firmware {}, radio {}, frequency {} kHz, toggle {}",
+                    info.firmware_version, info.radio, info.frequency_khz, info.toggle
+                ),
+                Message::Other(prefix, body) => {
+                    println!("Unhandled message (prefix {prefix}): {body}")
+                }
+            }
         }
     }
 }
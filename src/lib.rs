@@ -1,98 +1,713 @@
 use std::time::Duration;
 
 /// Baud rate of the device. For the JeeLink it is 57.6 KBd
-const BAUD_RATE: u32 = 57600;
+pub const BAUD_RATE: u32 = 57600;
 
 /// How long to listen before a time out error is issued.
 /// This number does not have a sinificant meaning except that a low timeout may cause high CPU usage.
-static TIMEOUT: Duration = Duration::from_millis(1000);
+pub static TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Number of times a command is (re-)sent before giving up on an acknowledgement.
+/// A single missed ack on a noisy serial line should not fail the call outright.
+const COMMAND_RETRIES: u32 = 3;
 
 /// Synchroneously receive data frames from the Jeelink device
 pub mod sync {
-    use super::{frame::Frame, frame::FrameRecorder, BAUD_RATE, TIMEOUT};
+    use super::{frame::FrameRecorder, frame::Message, BAUD_RATE, COMMAND_RETRIES, TIMEOUT};
     use serialport::SerialPort;
     use std::{
         cell::RefCell,
         collections::VecDeque,
-        io::{ErrorKind, Read},
+        convert::Infallible,
+        io::{ErrorKind, Read, Write},
+        net::TcpStream,
+        sync::mpsc,
+        thread,
+        time::{Duration, Instant},
     };
 
-    /// Listens on a serial device, the JeeLink v3c in this case.
+    /// Initial delay between reconnect attempts in [`spawn_resilient`], doubling on
+    /// every failed attempt up to [`MAX_RECONNECT_BACKOFF`].
+    const INITIAL_RECONNECT_BACKOFF: Duration = Duration::from_secs(1);
+
+    /// Upper bound on the delay between reconnect attempts in [`spawn_resilient`].
+    const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(30);
+
+    /// Listens for data frames over any byte transport, the JeeLink v3c in this case.
+    ///
+    /// Generic over the underlying transport `T` so the same frame-parsing logic can
+    /// be driven by a local serial port ([`SerialListener::bind`]), a ser2net TCP
+    /// bridge ([`SerialListener::connect_tcp`]), or (in tests) an in-memory byte
+    /// buffer via [`SerialListener::new`].
     ///
     /// A infinite iterator over received data frames can be obtained by the
     /// associated method [[SerialListener::incomming]].
-    pub struct SerialListener {
-        port: RefCell<Box<dyn SerialPort>>,
+    pub struct SerialListener<T: Read + Write = Box<dyn SerialPort>> {
+        port: RefCell<T>,
         recorder: RefCell<FrameRecorder>,
     }
 
-    impl SerialListener {
-        /// Bind the listener to a serial device, e.g. "/dev/ttyUSB0"
-        pub fn bind(addr: &str) -> Result<SerialListener, std::io::Error> {
-            let port = serialport::new(addr, BAUD_RATE).timeout(TIMEOUT).open()?;
-            let recorder = FrameRecorder::new();
-            Ok(SerialListener {
+    impl<T: Read + Write> SerialListener<T> {
+        /// Wrap an already-open transport.
+        pub fn new(port: T) -> Self {
+            SerialListener {
                 port: RefCell::new(port),
-                recorder: RefCell::new(recorder),
-            })
+                recorder: RefCell::new(FrameRecorder::new()),
+            }
         }
 
-        /// Blocks reading until at least one complete frame arrived.
-        pub fn accept(&self) -> std::io::Result<Vec<Frame>> {
-            let mut frames: Vec<Frame> = vec![];
-            let mut read_buf = [0u8; 1024];
-            let mut port = self.port.borrow_mut();
-            let mut recorder = self.recorder.borrow_mut();
-            while frames.is_empty() {
-                match port.read(&mut read_buf) {
-                    // read n bytes
-                    Ok(n) => {
-                        frames.extend(
-                            read_buf[..n]
-                                .iter()
-                                .filter_map(|&b| recorder.push(b as char))
-                                .filter_map(|s| s.parse::<Frame>().ok())
-                                .collect::<Vec<Frame>>(),
-                        );
-                    }
+        /// Blocks reading until at least one complete message arrived. A transient
+        /// I/O error (anything but a timeout) is logged and retried rather than
+        /// failing the call, so a single hiccup on a noisy line does not bring down
+        /// a caller like [`bin/sync.rs`](../../src/bin/sync.rs). Use
+        /// [`spawn_resilient`] if a dead transport should instead be detected and
+        /// reconnected.
+        pub fn accept(&self) -> std::io::Result<Vec<Message>> {
+            let mut messages: Vec<Message> = vec![];
+            while messages.is_empty() {
+                match self.read_once() {
+                    Ok(msgs) => messages.extend(msgs),
                     // no data received, keep trying
                     Err(ref e) if e.kind() == ErrorKind::TimedOut => (),
                     // Some other error happened
                     Err(e) => eprintln!("{:?}", e),
                 }
             }
-            Ok(frames)
+            Ok(messages)
+        }
+
+        /// Like [`accept`](Self::accept), but propagates every non-timeout error
+        /// (including the transport being closed) instead of logging and retrying.
+        /// Used by [`spawn_resilient`] to notice a dead transport and reconnect.
+        fn try_accept(&self) -> std::io::Result<Vec<Message>> {
+            loop {
+                match self.read_once() {
+                    Ok(msgs) if !msgs.is_empty() => return Ok(msgs),
+                    Ok(_) => continue,
+                    // no data received, keep trying
+                    Err(ref e) if e.kind() == ErrorKind::TimedOut => continue,
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        /// Performs a single read from the transport, returning any messages
+        /// completed by the bytes received. A zero-length read means the transport
+        /// was closed (e.g. a dropped ser2net connection) and is reported as
+        /// [`ErrorKind::UnexpectedEof`]; a timeout (including the `WouldBlock` a
+        /// TCP read timeout yields on Unix) is reported as [`ErrorKind::TimedOut`]
+        /// so callers can tell it apart from a real error.
+        fn read_once(&self) -> std::io::Result<Vec<Message>> {
+            let mut read_buf = [0u8; 1024];
+            let mut port = self.port.borrow_mut();
+            let mut recorder = self.recorder.borrow_mut();
+            match port.read(&mut read_buf) {
+                Ok(0) => Err(std::io::Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "transport closed",
+                )),
+                Ok(n) => Ok(read_buf[..n]
+                    .iter()
+                    .filter_map(|&b| recorder.push(b as char))
+                    .collect()),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => Err(ErrorKind::TimedOut.into()),
+                Err(e) => Err(e),
+            }
+        }
+
+        /// Send a raw command to the firmware, e.g. `"v"` to request the version or
+        /// `"868300f"` to set the frequency offset. Does not wait for a response.
+        pub fn send_command(&self, command: &str) -> std::io::Result<()> {
+            self.port.borrow_mut().write_all(command.as_bytes())
         }
 
-        /// Return an iterator that accepts indefinately incomming frames.
-        pub fn incomming(&self) -> Incoming {
+        /// Send a command and wait for a response line containing `expect`, e.g. the
+        /// firmware version string after sending `"v"`.
+        ///
+        /// The command is sent up to [`COMMAND_RETRIES`] times in all, again each
+        /// time no matching line arrives within `timeout`, so a single missed ack on
+        /// a noisy line does not fail the call.
+        pub fn send_command_and_wait(
+            &self,
+            command: &str,
+            expect: &str,
+            timeout: Duration,
+        ) -> std::io::Result<String> {
+            let mut port = self.port.borrow_mut();
+            for _ in 0..COMMAND_RETRIES {
+                port.write_all(command.as_bytes())?;
+                let deadline = Instant::now() + timeout;
+                while let Some(line) = read_line(&mut *port, deadline)? {
+                    if line.contains(expect) {
+                        return Ok(line);
+                    }
+                }
+            }
+            Err(std::io::Error::new(
+                ErrorKind::TimedOut,
+                format!("no acknowledgement for command {command:?} after {COMMAND_RETRIES} attempts"),
+            ))
+        }
+
+        /// Return an iterator that accepts indefinately incomming messages.
+        pub fn incomming(&self) -> Incoming<'_, T> {
             Incoming {
                 listener: self,
-                frame_buffer: VecDeque::new(),
+                message_buffer: VecDeque::new(),
             }
         }
     }
 
-    /// Iterator over incomming data frames
-    pub struct Incoming<'a> {
-        listener: &'a SerialListener,
-        frame_buffer: VecDeque<Frame>,
+    impl SerialListener<Box<dyn SerialPort>> {
+        /// Bind the listener to a serial device, e.g. "/dev/ttyUSB0"
+        pub fn bind(addr: &str) -> Result<Self, std::io::Error> {
+            let port = serialport::new(addr, BAUD_RATE).timeout(TIMEOUT).open()?;
+            Ok(Self::new(port))
+        }
+
+        /// Like [`bind`](Self::bind), but for long-running processes: spawns a
+        /// background thread that transparently reopens the device (e.g. after it
+        /// was unplugged and re-enumerated) instead of giving up.
+        pub fn spawn_resilient(addr: impl Into<String>) -> ResilientReceiver {
+            let addr = addr.into();
+            spawn_resilient(move || {
+                Ok(serialport::new(&addr, BAUD_RATE).timeout(TIMEOUT).open()?)
+            })
+        }
     }
 
-    impl<'a> Iterator for Incoming<'a> {
-        type Item = std::io::Result<Frame>;
+    impl SerialListener<TcpStream> {
+        /// Connect to a JeeLink exposed over the network via a ser2net bridge, e.g.
+        /// `host:2000`, instead of a local serial device.
+        pub fn connect_tcp(addr: &str) -> std::io::Result<Self> {
+            let stream = TcpStream::connect(addr)?;
+            stream.set_read_timeout(Some(TIMEOUT))?;
+            Ok(Self::new(stream))
+        }
+
+        /// Like [`connect_tcp`](Self::connect_tcp), but for long-running processes:
+        /// spawns a background thread that transparently reconnects to the ser2net
+        /// bridge instead of giving up.
+        pub fn spawn_resilient_tcp(addr: impl Into<String>) -> ResilientReceiver {
+            let addr = addr.into();
+            spawn_resilient(move || {
+                let stream = TcpStream::connect(&addr)?;
+                stream.set_read_timeout(Some(TIMEOUT))?;
+                Ok(stream)
+            })
+        }
+    }
+
+    /// Spawns a background thread that owns a transport built by `connect`,
+    /// forwarding parsed messages to the returned channel.
+    ///
+    /// If the transport errors, or `connect` itself fails, the thread waits and
+    /// retries with exponential backoff (starting at [`INITIAL_RECONNECT_BACKOFF`],
+    /// capped at [`MAX_RECONNECT_BACKOFF`]) and opens the transport again. Any
+    /// partially-recorded message is discarded across a reconnect, since a fresh
+    /// `FrameRecorder` is created along with the new transport. Once the returned
+    /// receiver is dropped, the thread exits the next time it has a message to
+    /// forward or a `connect()` attempt fails; it keeps running while a connected
+    /// transport delivers no messages.
+    pub fn spawn_resilient<T, F>(connect: F) -> ResilientReceiver
+    where
+        T: Read + Write,
+        F: Fn() -> std::io::Result<T> + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel();
+        let (canary_tx, canary_rx) = mpsc::channel::<Infallible>();
+        thread::spawn(move || {
+            let mut backoff = INITIAL_RECONNECT_BACKOFF;
+            loop {
+                let port = match connect() {
+                    Ok(port) => port,
+                    Err(_) => {
+                        if canary_rx.try_recv() == Err(mpsc::TryRecvError::Disconnected) {
+                            return;
+                        }
+                        thread::sleep(backoff);
+                        backoff = (backoff * 2).min(MAX_RECONNECT_BACKOFF);
+                        continue;
+                    }
+                };
+                backoff = INITIAL_RECONNECT_BACKOFF;
+                let listener = SerialListener::new(port);
+                // transport error: drop it and reconnect below
+                while let Ok(messages) = listener.try_accept() {
+                    for message in messages {
+                        if tx.send(message).is_err() {
+                            return;
+                        }
+                    }
+                }
+                thread::sleep(backoff);
+                backoff = (backoff * 2).min(MAX_RECONNECT_BACKOFF);
+            }
+        });
+        ResilientReceiver {
+            rx,
+            _canary: canary_tx,
+        }
+    }
+
+    /// The receiver returned by [`spawn_resilient`]. Behaves like a plain
+    /// `Receiver<Message>` (via [`Deref`](std::ops::Deref)); dropping it also
+    /// signals the background reconnect thread to stop, even while that thread
+    /// is stuck retrying a failed `connect()` and has no message to send yet.
+    pub struct ResilientReceiver {
+        rx: mpsc::Receiver<Message>,
+        _canary: mpsc::Sender<Infallible>,
+    }
+
+    impl std::ops::Deref for ResilientReceiver {
+        type Target = mpsc::Receiver<Message>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.rx
+        }
+    }
+
+    /// Reads a single `\n`-terminated line from `port`, ignoring the `FrameRecorder`
+    /// state machine. Returns `None` once `deadline` has passed without a full line.
+    fn read_line<T: Read>(port: &mut T, deadline: Instant) -> std::io::Result<Option<String>> {
+        let mut line = String::new();
+        let mut byte = [0u8; 1];
+        while Instant::now() < deadline {
+            match port.read(&mut byte) {
+                Ok(0) => continue,
+                Ok(_) if byte[0] == b'\n' => return Ok(Some(line.trim_end_matches('\r').to_string())),
+                Ok(_) => line.push(byte[0] as char),
+                Err(ref e) if matches!(e.kind(), ErrorKind::TimedOut | ErrorKind::WouldBlock) => {
+                    continue
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(None)
+    }
+
+    /// Iterator over incomming messages
+    pub struct Incoming<'a, T: Read + Write> {
+        listener: &'a SerialListener<T>,
+        message_buffer: VecDeque<Message>,
+    }
+
+    impl<'a, T: Read + Write> Iterator for Incoming<'a, T> {
+        type Item = std::io::Result<Message>;
 
         fn next(&mut self) -> Option<Self::Item> {
-            if self.frame_buffer.is_empty() {
+            if self.message_buffer.is_empty() {
                 match self.listener.accept() {
-                    Ok(frames) => self.frame_buffer.extend(frames),
+                    Ok(messages) => self.message_buffer.extend(messages),
                     Err(e) => return Some(Err(e)),
                 }
             }
             Some(Ok(self
-                .frame_buffer
+                .message_buffer
                 .pop_front()
-                .expect("Framebuffer empty")))
+                .expect("Message buffer empty")))
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::{spawn_resilient, Message, SerialListener};
+        use std::io::Cursor;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        #[test]
+        fn accepts_frames_from_an_in_memory_transport() {
+            // No real serial port or TCP bridge needed: any Read + Write works.
+            let data = b"OK 9 50 1 4 193 65\r\n".to_vec();
+            let listener = SerialListener::new(Cursor::new(data));
+
+            let messages = listener.accept().expect("read should succeed");
+            assert_eq!(messages.len(), 1);
+            match &messages[0] {
+                Message::LaCrosse(frame) => assert_eq!(frame.id, 50),
+                other => panic!("expected a LaCrosse frame, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn reconnects_after_a_failed_connect_attempt() {
+            let attempts = Arc::new(AtomicUsize::new(0));
+            let rx = {
+                let attempts = Arc::clone(&attempts);
+                spawn_resilient(move || {
+                    if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                        Err(std::io::Error::other("first connect fails"))
+                    } else {
+                        Ok(Cursor::new(b"OK 9 50 1 4 193 65\r\n".to_vec()))
+                    }
+                })
+            };
+
+            let message = rx
+                .recv_timeout(Duration::from_secs(5))
+                .expect("a message should eventually arrive once reconnected");
+            match message {
+                Message::LaCrosse(frame) => assert_eq!(frame.id, 50),
+                other => panic!("expected a LaCrosse frame, got {other:?}"),
+            }
+            assert!(attempts.load(Ordering::SeqCst) >= 2);
+        }
+
+        #[test]
+        fn reconnects_after_the_established_connection_dies_mid_stream() {
+            let attempts = Arc::new(AtomicUsize::new(0));
+            let rx = {
+                let attempts = Arc::clone(&attempts);
+                spawn_resilient(move || {
+                    let data = if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                        b"OK 9 50 1 4 193 65\r\n".to_vec()
+                    } else {
+                        b"OK 9 58 1 4 189 67\r\n".to_vec()
+                    };
+                    Ok(Cursor::new(data))
+                })
+            };
+
+            let first = rx
+                .recv_timeout(Duration::from_secs(5))
+                .expect("a frame should arrive over the first connection");
+            match first {
+                Message::LaCrosse(frame) => assert_eq!(frame.id, 50),
+                other => panic!("expected a LaCrosse frame, got {other:?}"),
+            }
+
+            // The Cursor is now exhausted, so the next read returns `Ok(0)`,
+            // exactly like a transport that died after already delivering data
+            // (e.g. an unplugged USB device or a dropped ser2net connection).
+            // try_accept() must treat that as fatal so the thread reconnects.
+            let second = rx
+                .recv_timeout(Duration::from_secs(5))
+                .expect("a message should arrive after reconnecting");
+            match second {
+                Message::LaCrosse(frame) => assert_eq!(frame.id, 58),
+                other => panic!("expected a LaCrosse frame, got {other:?}"),
+            }
+            assert!(attempts.load(Ordering::SeqCst) >= 2);
+        }
+    }
+}
+
+pub use asynchronous::SerialPortListener;
+pub use frame::{DeviceInfo, Frame, Message};
+
+/// Asynchroneously receive data frames from the Jeelink device
+mod asynchronous {
+    use super::{frame::FrameRecorder, frame::Message, BAUD_RATE, COMMAND_RETRIES};
+    use std::time::Duration;
+    use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+    use tokio::sync::mpsc;
+    use tokio_serial::SerialPortBuilderExt;
+
+    /// Initial delay between reconnect attempts in [`spawn_resilient`], doubling on
+    /// every failed attempt up to [`MAX_RECONNECT_BACKOFF`].
+    const INITIAL_RECONNECT_BACKOFF: Duration = Duration::from_secs(1);
+
+    /// Upper bound on the delay between reconnect attempts in [`spawn_resilient`].
+    const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(30);
+
+    /// Listens on a port for data frames, the JeeLink v3c in this case.
+    ///
+    /// Generic over the underlying transport so it can be driven by a local serial
+    /// port, a TCP stream, or anything else implementing [`AsyncRead`] + [`AsyncWrite`].
+    pub struct SerialPortListener<T> {
+        port: T,
+        recorder: FrameRecorder,
+    }
+
+    impl<T: AsyncRead + AsyncWrite + Unpin> SerialPortListener<T> {
+        /// Wrap an already opened port.
+        pub fn new(port: T) -> Self {
+            SerialPortListener {
+                port,
+                recorder: FrameRecorder::new(),
+            }
+        }
+
+        /// Reads a single byte from the port and feeds it to the recorder, returning
+        /// the message it completed, or `None` if it did not complete one.
+        pub async fn read_frame(&mut self) -> std::io::Result<Option<Message>> {
+            let mut buf = [0u8; 1];
+            self.port.read_exact(&mut buf).await?;
+            Ok(self.recorder.push(buf[0] as char))
+        }
+
+        /// Send a raw command to the firmware, e.g. `"v"` to request the version or
+        /// `"868300f"` to set the frequency offset. Does not wait for a response.
+        pub async fn send_command(&mut self, command: &str) -> std::io::Result<()> {
+            self.port.write_all(command.as_bytes()).await
+        }
+
+        /// Send a command and wait for a response line containing `expect`, e.g. the
+        /// firmware version string after sending `"v"`.
+        ///
+        /// The command is sent up to [`COMMAND_RETRIES`] times in all, again each
+        /// time no matching line arrives within `timeout`, so a single missed ack on
+        /// a noisy line does not fail the call.
+        pub async fn send_command_and_wait(
+            &mut self,
+            command: &str,
+            expect: &str,
+            timeout: Duration,
+        ) -> std::io::Result<String> {
+            for _ in 0..COMMAND_RETRIES {
+                self.port.write_all(command.as_bytes()).await?;
+                let deadline = tokio::time::Instant::now() + timeout;
+                loop {
+                    match tokio::time::timeout_at(deadline, self.read_line()).await {
+                        Ok(Ok(line)) if line.contains(expect) => return Ok(line),
+                        Ok(Ok(_)) => (),
+                        Ok(Err(e)) => return Err(e),
+                        Err(_) => break, // timed out waiting for this attempt, retry
+                    }
+                }
+            }
+            Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                format!("no acknowledgement for command {command:?} after {COMMAND_RETRIES} attempts"),
+            ))
+        }
+
+        /// Reads a single `\n`-terminated line from the port, ignoring the
+        /// `FrameRecorder` state machine.
+        async fn read_line(&mut self) -> std::io::Result<String> {
+            let mut line = String::new();
+            let mut byte = [0u8; 1];
+            loop {
+                self.port.read_exact(&mut byte).await?;
+                if byte[0] == b'\n' {
+                    return Ok(line.trim_end_matches('\r').to_string());
+                }
+                line.push(byte[0] as char);
+            }
+        }
+    }
+
+    impl SerialPortListener<tokio::net::TcpStream> {
+        /// Connect to a JeeLink exposed over the network via a ser2net bridge, e.g.
+        /// `host:2000`, instead of a local serial device.
+        pub async fn connect_tcp(addr: &str) -> std::io::Result<Self> {
+            let stream = tokio::net::TcpStream::connect(addr).await?;
+            Ok(Self::new(stream))
+        }
+
+        /// Like [`connect_tcp`](Self::connect_tcp), but for long-running processes:
+        /// spawns a background task that transparently reconnects to the ser2net
+        /// bridge instead of giving up.
+        pub fn spawn_resilient_tcp(addr: impl Into<String>) -> mpsc::UnboundedReceiver<Message> {
+            let addr = addr.into();
+            spawn_resilient(move || {
+                let addr = addr.clone();
+                async move { tokio::net::TcpStream::connect(&addr).await }
+            })
+        }
+    }
+
+    impl SerialPortListener<tokio_serial::SerialStream> {
+        /// Like [`SerialListener::bind`](super::sync::SerialListener::bind), but
+        /// async and for long-running processes: spawns a background task that
+        /// transparently reopens the device (e.g. after it was unplugged and
+        /// re-enumerated) instead of giving up.
+        pub fn spawn_resilient(addr: impl Into<String>) -> mpsc::UnboundedReceiver<Message> {
+            let addr = addr.into();
+            spawn_resilient(move || {
+                let addr = addr.clone();
+                async move {
+                    let mut port = tokio_serial::new(&addr, BAUD_RATE).open_native_async()?;
+                    #[cfg(unix)]
+                    port.set_exclusive(false)?;
+                    Ok(port)
+                }
+            })
+        }
+    }
+
+    /// Spawns a background task that owns a transport built by `connect`,
+    /// forwarding parsed messages to the returned channel.
+    ///
+    /// If the transport errors, or `connect` itself fails, the task waits and
+    /// retries with exponential backoff (starting at [`INITIAL_RECONNECT_BACKOFF`],
+    /// capped at [`MAX_RECONNECT_BACKOFF`]) and opens the transport again. Any
+    /// partially-recorded message is discarded across a reconnect, since a fresh
+    /// `FrameRecorder` is created along with the new transport. Once the returned
+    /// receiver is dropped, the task exits the next time it has a message to
+    /// forward or a `connect()` attempt fails; it keeps running while a connected
+    /// transport delivers no messages.
+    pub fn spawn_resilient<T, F, Fut>(connect: F) -> mpsc::UnboundedReceiver<Message>
+    where
+        T: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+        F: Fn() -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = std::io::Result<T>> + Send,
+    {
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::task::spawn(async move {
+            let mut backoff = INITIAL_RECONNECT_BACKOFF;
+            loop {
+                let port = match connect().await {
+                    Ok(port) => port,
+                    Err(_) => {
+                        if tx.is_closed() {
+                            return;
+                        }
+                        tokio::time::sleep(backoff).await;
+                        backoff = (backoff * 2).min(MAX_RECONNECT_BACKOFF);
+                        continue;
+                    }
+                };
+                backoff = INITIAL_RECONNECT_BACKOFF;
+                let mut listener = SerialPortListener::new(port);
+                loop {
+                    match listener.read_frame().await {
+                        Ok(Some(message)) => {
+                            if tx.send(message).is_err() {
+                                return;
+                            }
+                        }
+                        Ok(None) => (),
+                        // transport error: drop it and reconnect below
+                        Err(_) => break,
+                    }
+                }
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(MAX_RECONNECT_BACKOFF);
+            }
+        });
+        rx
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::{spawn_resilient, Message, SerialPortListener};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use tokio::io::{AsyncWriteExt, DuplexStream};
+        use tokio::sync::mpsc;
+
+        #[tokio::test]
+        async fn accepts_frames_from_an_in_memory_transport() {
+            // No real serial port or TCP bridge needed: any AsyncRead + AsyncWrite works.
+            let (client, mut server) = tokio::io::duplex(64);
+            let mut listener = SerialPortListener::new(client);
+            server.write_all(b"OK 9 50 1 4 193 65\r\n").await.unwrap();
+
+            // read_frame() consumes a single byte per call; keep calling until it
+            // has recorded a complete message.
+            let message = loop {
+                if let Some(message) = listener.read_frame().await.expect("read should succeed") {
+                    break message;
+                }
+            };
+            match message {
+                Message::LaCrosse(frame) => assert_eq!(frame.id, 50),
+                other => panic!("expected a LaCrosse frame, got {other:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn reconnects_after_a_failed_connect_attempt() {
+            let attempts = Arc::new(AtomicUsize::new(0));
+            let (transport_tx, mut transport_rx) = mpsc::unbounded_channel::<DuplexStream>();
+
+            let mut rx = {
+                let attempts = Arc::clone(&attempts);
+                spawn_resilient(move || {
+                    let attempts = Arc::clone(&attempts);
+                    let transport_tx = transport_tx.clone();
+                    async move {
+                        if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                            Err(std::io::Error::other("first connect fails"))
+                        } else {
+                            let (client, server) = tokio::io::duplex(64);
+                            let _ = transport_tx.send(server);
+                            Ok(client)
+                        }
+                    }
+                })
+            };
+
+            let mut server = transport_rx
+                .recv()
+                .await
+                .expect("a transport should eventually be (re)connected");
+            server
+                .write_all(b"OK 9 50 1 4 193 65\r\n")
+                .await
+                .unwrap();
+
+            let message = rx
+                .recv()
+                .await
+                .expect("a message should eventually arrive once reconnected");
+            match message {
+                Message::LaCrosse(frame) => assert_eq!(frame.id, 50),
+                other => panic!("expected a LaCrosse frame, got {other:?}"),
+            }
+            assert!(attempts.load(Ordering::SeqCst) >= 2);
+        }
+
+        #[tokio::test]
+        async fn reconnects_after_the_established_connection_dies_mid_stream() {
+            let (transport_tx, mut transport_rx) = mpsc::unbounded_channel::<DuplexStream>();
+
+            let mut rx = {
+                let transport_tx = transport_tx.clone();
+                spawn_resilient(move || {
+                    let transport_tx = transport_tx.clone();
+                    async move {
+                        let (client, server) = tokio::io::duplex(64);
+                        let _ = transport_tx.send(server);
+                        Ok(client)
+                    }
+                })
+            };
+
+            let mut server = transport_rx
+                .recv()
+                .await
+                .expect("the first transport should connect");
+            server
+                .write_all(b"OK 9 50 1 4 193 65\r\n")
+                .await
+                .unwrap();
+
+            let first = rx
+                .recv()
+                .await
+                .expect("a frame should arrive over the first connection");
+            match first {
+                Message::LaCrosse(frame) => assert_eq!(frame.id, 50),
+                other => panic!("expected a LaCrosse frame, got {other:?}"),
+            }
+
+            // Drop the server half to simulate the transport dying mid-stream
+            // (e.g. an unplugged USB device or a dropped ser2net connection).
+            // The listener's next read then errors, and the task should
+            // reconnect rather than getting stuck.
+            drop(server);
+
+            let mut server = transport_rx
+                .recv()
+                .await
+                .expect("a second transport should connect after reconnecting");
+            server
+                .write_all(b"OK 9 58 1 4 189 67\r\n")
+                .await
+                .unwrap();
+
+            let second = rx
+                .recv()
+                .await
+                .expect("a message should arrive after reconnecting");
+            match second {
+                Message::LaCrosse(frame) => assert_eq!(frame.id, 58),
+                other => panic!("expected a LaCrosse frame, got {other:?}"),
+            }
         }
     }
 }
@@ -175,42 +790,122 @@ mod frame {
         }
     }
 
+    /// A message recognized by the [`FrameRecorder`]. New protocols are added by
+    /// registering a prefix and parser in [`PROTOCOLS`], not by touching the state
+    /// machine.
+    #[derive(Debug, Clone)]
+    pub enum Message {
+        /// A LaCrosse temperature/humidity [`Frame`].
+        LaCrosse(Frame),
+        /// The firmware banner emitted at startup and after a `v` command.
+        DeviceInfo(DeviceInfo),
+        /// A line that matched a known prefix but could not be parsed by its
+        /// protocol's parser. Carries the index into [`PROTOCOLS`] of the prefix
+        /// that matched, and the raw line body.
+        Other(u8, String),
+    }
+
+    /// Firmware banner line, e.g.
+    /// `[LaCrosseITPlusReader.10.1s (RFM69CW f:868300 t:30~3)]`, emitted at startup
+    /// and after sending the `v` command.
+    #[derive(Debug, Clone)]
+    pub struct DeviceInfo {
+        pub firmware_version: String,
+        pub radio: String,
+        pub frequency_khz: u32,
+        pub toggle: String,
+    }
+
+    impl FromStr for DeviceInfo {
+        type Err = &'static str;
+
+        /// Parses a banner body (the leading `[` and trailing `\r\n` already
+        /// stripped by the `FrameRecorder`), e.g.
+        /// `LaCrosseITPlusReader.10.1s (RFM69CW f:868300 t:30~3)]`.
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let s = s.strip_suffix(']').ok_or("missing closing bracket")?;
+            let (name_and_version, radio_part) =
+                s.split_once(" (").ok_or("missing radio section")?;
+            let (_, firmware_version) = name_and_version
+                .split_once('.')
+                .ok_or("missing firmware version")?;
+            let radio_part = radio_part.strip_suffix(')').ok_or("missing closing paren")?;
+
+            let mut fields = radio_part.split(' ');
+            let radio = fields.next().ok_or("missing radio module")?;
+            let frequency_khz: u32 = fields
+                .next()
+                .and_then(|f| f.strip_prefix("f:"))
+                .ok_or("missing frequency")?
+                .parse()
+                .map_err(|_| "invalid frequency")?;
+            let toggle = fields
+                .next()
+                .and_then(|f| f.strip_prefix("t:"))
+                .ok_or("missing toggle interval")?;
+
+            Ok(DeviceInfo {
+                firmware_version: firmware_version.to_string(),
+                radio: radio.to_string(),
+                frequency_khz,
+                toggle: toggle.to_string(),
+            })
+        }
+    }
+
+    /// A `(prefix, parser)` pair describing one JeeLink line protocol recognized by
+    /// the [`FrameRecorder`].
+    struct Protocol {
+        /// The literal prefix that activates this protocol, e.g. `"OK 9 "`.
+        prefix: &'static str,
+        /// Parses the line body (prefix and trailing `\r\n` already stripped). The
+        /// `index` argument is this protocol's own position in [`PROTOCOLS`],
+        /// supplied by the dispatcher in [`FrameRecorder::push`] so a parser never
+        /// has to hardcode (and risk mismatching) its own array index.
+        parse: fn(index: usize, body: &str) -> Message,
+    }
+
+    /// Prefixes recognized at the start of a line, along with the parser used once
+    /// a complete line for that prefix has been recorded. Adding a new JeeLink
+    /// sensor protocol is a matter of adding an entry here.
+    static PROTOCOLS: &[Protocol] = &[
+        Protocol {
+            prefix: "OK 9 ",
+            parse: |index, body| match body.parse::<Frame>() {
+                Ok(frame) => Message::LaCrosse(frame),
+                Err(_) => Message::Other(index as u8, body.to_string()),
+            },
+        },
+        Protocol {
+            prefix: "[",
+            parse: |index, body| match body.parse::<DeviceInfo>() {
+                Ok(info) => Message::DeviceInfo(info),
+                Err(_) => Message::Other(index as u8, body.to_string()),
+            },
+        },
+    ];
+
     /// States of the FrameRecorder state machine
     enum FrameRecorderState {
         NotRecording,
-        Activating(usize),
-        Recording,
+        /// Narrowing down which entries of [`PROTOCOLS`] still match the chars seen
+        /// so far. `matched` is how many chars of the prefix have matched.
+        Activating {
+            matched: usize,
+            candidates: Vec<usize>,
+        },
+        /// Recording the body of a line for the matched protocol (index into
+        /// [`PROTOCOLS`]).
+        Recording(usize),
+        /// Saw `\r` while recording; checking whether the next char is `\n`.
         Terminating(usize),
     }
 
-    impl FrameRecorderState {
-        /// Move state forward
-        fn next(&mut self, len_activation: usize, len_termination: usize) {
-            match self {
-                FrameRecorderState::NotRecording => *self = FrameRecorderState::Activating(0),
-                FrameRecorderState::Activating(level) => {
-                    *level += 1;
-                    if *level >= (len_activation - 1) {
-                        *self = FrameRecorderState::Recording;
-                    }
-                }
-                FrameRecorderState::Recording => *self = FrameRecorderState::Terminating(0),
-                FrameRecorderState::Terminating(level) => {
-                    *level += 1;
-                    if *level >= (len_termination - 1) {
-                        *self = FrameRecorderState::NotRecording
-                    }
-                }
-            }
-        }
-    }
-
-    /// Records frame strings from a stream of chars
+    /// Records message lines from a stream of chars, recognizing any prefix
+    /// registered in [`PROTOCOLS`].
     pub struct FrameRecorder {
         buffer: String,
         state: FrameRecorderState,
-        activate_chars: &'static [char],
-        terminate_char: &'static [char],
     }
 
     impl FrameRecorder {
@@ -218,52 +913,72 @@ mod frame {
             FrameRecorder {
                 buffer: String::new(),
                 state: FrameRecorderState::NotRecording,
-                activate_chars: &['O', 'K', ' ', '9', ' '],
-                terminate_char: &['\r', '\n'],
             }
         }
 
         /// Push new char to the FrameRecorder.
-        /// Returns a completed frame sting or None, if no frame is completed.
-        pub fn push(&mut self, char: char) -> Option<String> {
-            let n_act = self.activate_chars.len();
-            let n_term = self.terminate_char.len();
-            match self.state {
+        /// Returns a completed [`Message`] or None, if no message is completed yet.
+        pub fn push(&mut self, char: char) -> Option<Message> {
+            match &self.state {
                 FrameRecorderState::NotRecording => {
-                    if char == self.activate_chars[0] {
-                        self.state.next(n_act, n_term);
-                    }
+                    let candidates: Vec<usize> = PROTOCOLS
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, p)| p.prefix.starts_with(char))
+                        .map(|(i, _)| i)
+                        .collect();
+                    self.state = match candidates.as_slice() {
+                        [] => FrameRecorderState::NotRecording,
+                        [only] if PROTOCOLS[*only].prefix.chars().count() == 1 => {
+                            FrameRecorderState::Recording(*only)
+                        }
+                        _ => FrameRecorderState::Activating {
+                            matched: 1,
+                            candidates,
+                        },
+                    };
                     None
                 }
-                FrameRecorderState::Activating(level) => {
-                    if char == self.activate_chars[level + 1] {
-                        self.state.next(n_act, n_term)
-                    } else {
-                        self.state = FrameRecorderState::NotRecording;
-                    }
+                FrameRecorderState::Activating { matched, candidates } => {
+                    let matched = matched + 1;
+                    let candidates: Vec<usize> = candidates
+                        .iter()
+                        .copied()
+                        .filter(|&i| PROTOCOLS[i].prefix.chars().nth(matched - 1) == Some(char))
+                        .collect();
+                    self.state = match candidates.as_slice() {
+                        [] => FrameRecorderState::NotRecording,
+                        _ => match candidates
+                            .iter()
+                            .find(|&&i| PROTOCOLS[i].prefix.chars().count() == matched)
+                        {
+                            Some(&done) => FrameRecorderState::Recording(done),
+                            None => FrameRecorderState::Activating { matched, candidates },
+                        },
+                    };
                     None
                 }
-                FrameRecorderState::Recording => {
+                FrameRecorderState::Recording(protocol) => {
+                    let protocol = *protocol;
                     self.buffer.push(char);
-                    if char == self.terminate_char[0] {
-                        self.state.next(n_act, n_term);
-                    }
+                    self.state = if char == '\r' {
+                        FrameRecorderState::Terminating(protocol)
+                    } else {
+                        FrameRecorderState::Recording(protocol)
+                    };
                     None
                 }
-                FrameRecorderState::Terminating(level) => {
+                FrameRecorderState::Terminating(protocol) => {
+                    let protocol = *protocol;
                     self.buffer.push(char);
-                    if char == self.terminate_char[level + 1] {
-                        self.state.next(n_act, n_term)
+                    if char == '\n' {
+                        let body = self.buffer[..self.buffer.len() - 2].to_string();
+                        self.buffer.clear();
+                        self.state = FrameRecorderState::NotRecording;
+                        Some((PROTOCOLS[protocol].parse)(protocol, &body))
                     } else {
-                        self.state = FrameRecorderState::Recording
-                    }
-                    match self.state {
-                        FrameRecorderState::NotRecording => {
-                            let frame = self.buffer.clone();
-                            self.buffer.clear();
-                            Some(frame[..frame.len() - 2].to_string())
-                        }
-                        _ => None,
+                        self.state = FrameRecorderState::Recording(protocol);
+                        None
                     }
                 }
             }
@@ -272,40 +987,83 @@ mod frame {
 
     #[cfg(test)]
     mod test {
-        use super::FrameRecorder;
+        use super::{DeviceInfo, FrameRecorder, Message};
 
         #[test]
         fn test_frame_construction() {
             let data = [
+                // A mid-line "OK 9 " that gets interrupted by the boot banner before
+                // any digits follow: the activation sequence already committed to the
+                // LaCrosse protocol, so this is recorded (and discarded) as an
+                // unparseable LaCrosse line, not as a DeviceInfo banner.
                 "OK 9 50 1 4 193 65\r\nOK 9 58 1 4 189 67\r\nOK 9 1 1 4 189 65\r\nOK 0 9 1",
                 "OK 9 ",
                 "\n[LaCrosseITPlusReader.10.1s (RFM69CW f:868300 t:30~3)",
                 "]\r\n",
                 "OK 9 13 1 4 181 ",
                 "65\r\n",
+                // A standalone banner line, e.g. emitted after a `v` command.
+                "[LaCrosseITPlusReader.10.1s (RFM69CW f:868300 t:30~3)]\r\n",
                 "OK 9 18 1 4 193 61\r\n",
                 "OK 9 1 1 4 188 64\r\n",
             ];
 
             let mut recorder = FrameRecorder::new();
 
-            let res: Vec<String> = data
+            let mut res: Vec<Message> = data
                 .iter()
                 .flat_map(|s| s.chars())
                 .filter_map(|c| recorder.push(c))
                 .collect();
 
-            let expect = [
-                "50 1 4 193 65",
-                "58 1 4 189 67",
-                "1 1 4 189 65",
-                "13 1 4 181 65",
-                "18 1 4 193 61",
-                "1 1 4 188 64",
-            ];
-            res.into_iter()
-                .zip(expect.into_iter())
-                .for_each(|(r, e)| assert_eq!(r, e));
+            // The standalone banner line is recognized as a DeviceInfo.
+            match res.remove(5) {
+                Message::DeviceInfo(info) => {
+                    assert_eq!(info.firmware_version, "10.1s");
+                    assert_eq!(info.radio, "RFM69CW");
+                    assert_eq!(info.frequency_khz, 868300);
+                    assert_eq!(info.toggle, "30~3");
+                }
+                other => panic!("expected a DeviceInfo banner, got {other:?}"),
+            }
+
+            // The interrupted "OK 9 " + banner line is recorded as an unparseable
+            // LaCrosse line, exactly as it was silently dropped before this protocol
+            // registry existed.
+            match res.remove(3) {
+                Message::Other(0, _) => (),
+                other => panic!("expected an unparseable LaCrosse line, got {other:?}"),
+            }
+
+            // (id, humidity) pairs parsed from "50 1 4 193 65" etc.
+            let expect = [(50, 65), (58, 67), (1, 65), (13, 65), (18, 61), (1, 64)];
+
+            let frames: Vec<_> = res
+                .into_iter()
+                .map(|m| match m {
+                    Message::LaCrosse(frame) => frame,
+                    other => panic!("expected a LaCrosse frame, got {other:?}"),
+                })
+                .collect();
+            assert_eq!(frames.len(), expect.len());
+            frames
+                .iter()
+                .zip(expect.iter())
+                .for_each(|(frame, (id, humidity))| {
+                    assert_eq!(frame.id, *id);
+                    assert_eq!(frame.humidity, *humidity);
+                });
+        }
+
+        #[test]
+        fn test_device_info_parsing() {
+            let info: DeviceInfo = "LaCrosseITPlusReader.10.1s (RFM69CW f:868300 t:30~3)]"
+                .parse()
+                .unwrap();
+            assert_eq!(info.firmware_version, "10.1s");
+            assert_eq!(info.radio, "RFM69CW");
+            assert_eq!(info.frequency_khz, 868300);
+            assert_eq!(info.toggle, "30~3");
         }
     }
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 use read_jeelink::sync::SerialListener;
+use read_jeelink::Message;
 
 static DEVICE: &str = "/dev/tty.usbserial-AL006PX8";
 
@@ -6,12 +7,18 @@ fn main() -> std::io::Result<()> {
     println!("Open port on device");
     let listener = SerialListener::bind(DEVICE)?;
     println!("Ready to read");
-    for frame in listener.incomming() {
-        let frame = frame?;
-        println!(
-            "Sensor {:2}: Temperatur {:4}, Humidity {:2}, weak battery: {}, new battery: {}",
-            frame.id, frame.temperature, frame.humidity, frame.weak_battery, frame.new_battery
-        );
+    for message in listener.incomming() {
+        match message? {
+            Message::LaCrosse(frame) => println!(
+                "Sensor {:2}: Temperatur {:4}, Humidity {:2}, weak battery: {}, new battery: {}",
+                frame.id, frame.temperature, frame.humidity, frame.weak_battery, frame.new_battery
+            ),
+            Message::DeviceInfo(info) => println!(
+                "Device info: firmware {}, radio {}, frequency {} kHz, toggle {}",
+                info.firmware_version, info.radio, info.frequency_khz, info.toggle
+            ),
+            Message::Other(prefix, body) => println!("Unhandled message (prefix {prefix}): {body}"),
+        }
     }
     Ok(())
 }